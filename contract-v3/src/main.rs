@@ -0,0 +1,206 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+// We need to explicitly import the std alloc crate and `alloc::string::String` as we're in a
+// `no_std` environment.
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::NamedKeys, ApiError, CLType, CLValue, ContractPackageHash, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Parameter, URef,
+};
+
+const PACKAGE_KEY_NAME: &str = "counter_package";
+const CONTRACT_HASH_KEY_NAME: &str = "counter_contract_hash";
+const CONTRACT_VERSION_KEY_NAME: &str = "counter_contract_version";
+const COUNT_KEY_NAME: &str = "count_key";
+const ARG_STEP: &str = "step";
+
+// Names for the dictionary-backed event log, modeled on the Casper Event Standard so that
+// off-chain indexers have a predictable place to read contract activity from.
+const EVENTS_DICT: &str = "__events";
+const EVENTS_LENGTH_KEY: &str = "__events_length";
+const EVENTS_SCHEMA_KEY: &str = "__events_schema";
+const EVENT_NAME_COUNT_INCREMENTED: &str = "CountIncremented";
+
+/// An error enum which can be converted to a `u16` so it can be returned as an `ApiError::User`.
+#[repr(u16)]
+enum Error {
+    InvalidStep = 2,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+/// Adds a third version of the counter contract to `counter_package`, again reusing the existing
+/// `count_key` and event-log URefs so upgrading past v2 still preserves the counter's value and
+/// event history.
+#[no_mangle]
+pub extern "C" fn call() {
+    let package_hash: ContractPackageHash = runtime::get_key(PACKAGE_KEY_NAME)
+        .unwrap_or_revert()
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .unwrap_or_revert();
+
+    let count_uref: URef = runtime::get_key(COUNT_KEY_NAME)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+
+    let events_dict_uref: URef = runtime::get_key(EVENTS_DICT)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    let events_length_uref: URef = runtime::get_key(EVENTS_LENGTH_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    let events_schema_uref: URef = runtime::get_key(EVENTS_SCHEMA_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(String::from(COUNT_KEY_NAME), count_uref.into());
+    named_keys.insert(String::from(EVENTS_DICT), events_dict_uref.into());
+    named_keys.insert(String::from(EVENTS_LENGTH_KEY), events_length_uref.into());
+    named_keys.insert(String::from(EVENTS_SCHEMA_KEY), events_schema_uref.into());
+
+    let mut entry_points = EntryPoints::new();
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "increment_count",
+        alloc::vec![Parameter::new(ARG_STEP, CLType::U32)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "decrement_count",
+        alloc::vec![Parameter::new(ARG_STEP, CLType::U32)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_count",
+        Vec::new(),
+        CLType::U32,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "double_count",
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let (contract_hash, contract_version) =
+        storage::add_contract_version(package_hash, entry_points, named_keys);
+
+    runtime::put_key(CONTRACT_HASH_KEY_NAME, contract_hash.into());
+    runtime::put_key(
+        CONTRACT_VERSION_KEY_NAME,
+        storage::new_uref(contract_version).into(),
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn increment_count() {
+    let step: u32 = runtime::get_named_arg(ARG_STEP);
+    if step == 0 {
+        runtime::revert(Error::InvalidStep);
+    }
+
+    let count_uref: URef = runtime::get_key(COUNT_KEY_NAME)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+
+    storage::add(count_uref, step);
+
+    let count: u32 = storage::read(count_uref).unwrap_or_revert().unwrap_or_revert();
+    emit_count_incremented(count);
+}
+
+#[no_mangle]
+pub extern "C" fn decrement_count() {
+    let step: u32 = runtime::get_named_arg(ARG_STEP);
+    if step == 0 {
+        runtime::revert(Error::InvalidStep);
+    }
+
+    let count_uref: URef = runtime::get_key(COUNT_KEY_NAME)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+
+    let count: u32 = storage::read(count_uref).unwrap_or_revert().unwrap_or_revert();
+    storage::write(count_uref, count.saturating_sub(step));
+}
+
+#[no_mangle]
+pub extern "C" fn get_count() {
+    let count_uref: URef = runtime::get_key(COUNT_KEY_NAME)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+
+    let count: u32 = storage::read(count_uref).unwrap_or_revert().unwrap_or_revert();
+    runtime::ret(CLValue::from_t(count).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn double_count() {
+    let count_uref: URef = runtime::get_key(COUNT_KEY_NAME)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+
+    let count: u32 = storage::read(count_uref).unwrap_or_revert().unwrap_or_revert();
+    storage::write(count_uref, count.saturating_mul(2));
+}
+
+/// Appends a `CountIncremented` event to the `__events` dictionary at the current
+/// `__events_length` index, then bumps the length counter, so off-chain indexers can replay
+/// contract activity without relying on account named keys.
+fn emit_count_incremented(count: u32) {
+    let events_dict_uref: URef = runtime::get_key(EVENTS_DICT)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+
+    let events_length_uref: URef = runtime::get_key(EVENTS_LENGTH_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+
+    let length: u32 = storage::read(events_length_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+
+    let event = (String::from(EVENT_NAME_COUNT_INCREMENTED), count);
+    let event_value = CLValue::from_t(event).unwrap_or_revert();
+    storage::dictionary_put(events_dict_uref, &length.to_string(), event_value);
+
+    storage::write(events_length_uref, length + 1);
+}
@@ -16,18 +16,27 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    contracts::NamedKeys, ApiError, CLType, EntryPoint, EntryPointAccess, EntryPointType,
-    EntryPoints, Key, URef,
+    contracts::NamedKeys, ApiError, CLType, CLValue, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, Key, Parameter, URef,
 };
 
 const KEY_NAME: &str = "my-key-name";
 const RUNTIME_ARG_NAME: &str = "message";
+const ARG_STEP: &str = "step";
+
+// Names for the dictionary-backed event log, modeled on the Casper Event Standard so that
+// off-chain indexers have a predictable place to read contract activity from.
+const EVENTS_DICT: &str = "__events";
+const EVENTS_LENGTH_KEY: &str = "__events_length";
+const EVENTS_SCHEMA_KEY: &str = "__events_schema";
+const EVENT_NAME_COUNT_INCREMENTED: &str = "CountIncremented";
 
 /// An error enum which can be converted to a `u16` so it can be returned as an `ApiError::User`.
 #[repr(u16)]
 enum Error {
     KeyAlreadyExists = 0,
     KeyMismatch = 1,
+    InvalidStep = 2,
 }
 
 impl From<Error> for ApiError {
@@ -42,11 +51,39 @@ pub extern "C" fn call() {
     let mut named_keys = NamedKeys::new();
     named_keys.insert(String::from("count_key"), count.into());
 
+    // Also expose the URef at the account level so a later contract upgrade can look it up
+    // and carry the counter's value forward into the new version's named keys.
+    runtime::put_key("count_key", count.into());
+
+    // `storage::new_dictionary` registers the dictionary under the calling context's named
+    // keys, so pull it back out and move it into the contract's own named keys below.
+    storage::new_dictionary(EVENTS_DICT).unwrap_or_revert();
+    let events_dict_uref = *runtime::get_key(EVENTS_DICT)
+        .unwrap_or_revert()
+        .as_uref()
+        .unwrap_or_revert();
+    runtime::remove_key(EVENTS_DICT);
+    named_keys.insert(String::from(EVENTS_DICT), events_dict_uref.into());
+
+    let events_length = storage::new_uref(0u32);
+    named_keys.insert(String::from(EVENTS_LENGTH_KEY), events_length.into());
+
+    let events_schema = storage::new_uref(String::from(
+        "CountIncremented(count: U32)",
+    ));
+    named_keys.insert(String::from(EVENTS_SCHEMA_KEY), events_schema.into());
+
+    // Also expose these URefs at the account level, same as `count_key` above, so a later
+    // contract upgrade can carry the event log forward into the new version's named keys.
+    runtime::put_key(EVENTS_DICT, events_dict_uref.into());
+    runtime::put_key(EVENTS_LENGTH_KEY, events_length.into());
+    runtime::put_key(EVENTS_SCHEMA_KEY, events_schema.into());
+
     let mut entry_points = EntryPoints::new();
 
     entry_points.add_entry_point(EntryPoint::new(
         "increment_count",
-        Vec::new(),
+        alloc::vec![Parameter::new(ARG_STEP, CLType::U32)],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
@@ -64,10 +101,43 @@ pub extern "C" fn call() {
 
 #[no_mangle]
 pub extern "C" fn increment_count() {
+    let step: u32 = runtime::get_named_arg(ARG_STEP);
+    if step == 0 {
+        runtime::revert(Error::InvalidStep);
+    }
+
     let count_uref: URef = runtime::get_key("count_key")
         .unwrap_or_revert()
         .into_uref()
         .unwrap_or_revert();
 
-    storage::add(count_uref, 1);
+    storage::add(count_uref, step);
+
+    let count: u32 = storage::read(count_uref).unwrap_or_revert().unwrap_or_revert();
+    emit_count_incremented(count);
+}
+
+/// Appends a `CountIncremented` event to the `__events` dictionary at the current
+/// `__events_length` index, then bumps the length counter, so off-chain indexers can replay
+/// contract activity without relying on account named keys.
+fn emit_count_incremented(count: u32) {
+    let events_dict_uref: URef = runtime::get_key(EVENTS_DICT)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+
+    let events_length_uref: URef = runtime::get_key(EVENTS_LENGTH_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+
+    let length: u32 = storage::read(events_length_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+
+    let event = (String::from(EVENT_NAME_COUNT_INCREMENTED), count);
+    let event_value = CLValue::from_t(event).unwrap_or_revert();
+    storage::dictionary_put(events_dict_uref, &length.to_string(), event_value);
+
+    storage::write(events_length_uref, length + 1);
 }
@@ -0,0 +1,27 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+// We need to explicitly import the std alloc crate as we're in a `no_std` environment.
+extern crate alloc;
+
+use casper_contract::contract_api::runtime;
+use casper_types::{runtime_args, ContractHash, RuntimeArgs};
+
+const ARG_COUNTER_CONTRACT_HASH: &str = "counter_contract_hash";
+
+/// Calls `increment_count` on an already-installed counter contract, demonstrating
+/// contract-to-contract invocation rather than only account-to-contract deploys.
+#[no_mangle]
+pub extern "C" fn call() {
+    let counter_contract_hash: ContractHash =
+        runtime::get_named_arg(ARG_COUNTER_CONTRACT_HASH);
+
+    runtime::call_contract::<()>(
+        counter_contract_hash,
+        "increment_count",
+        runtime_args! { "step" => 1u32 },
+    );
+}
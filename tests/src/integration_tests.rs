@@ -4,20 +4,53 @@ fn main() {
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
+    use std::{fs, path::PathBuf};
 
     use casper_engine_test_support::{
         DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
         DEFAULT_ACCOUNT_ADDR, DEFAULT_PAYMENT, PRODUCTION_RUN_GENESIS_REQUEST,
     };
     use casper_execution_engine::core::{engine_state::Error as EngineStateError, execution};
-    use casper_types::{runtime_args, ApiError, ContractHash, Key, RuntimeArgs};
+    use casper_types::{runtime_args, ApiError, ContractHash, ContractPackageHash, Key, RuntimeArgs};
 
     // Define `KEY` constant to match that in the contract.
     const KEY: &str = "my-key-name";
     const VALUE: &str = "hello world";
     const RUNTIME_ARG_NAME: &str = "message";
     const CONTRACT_WASM: &str = "contract.wasm";
+    const CONTRACT_V2_WASM: &str = "contract-v2.wasm";
+    const CONTRACT_V3_WASM: &str = "contract-v3.wasm";
+    const CONTRACT_CALL_WASM: &str = "counter-call.wasm";
+
+    // Gas ceilings are deliberately generous; their purpose is to catch a regression that
+    // multiplies a deploy's cost, not to pin the exact gas figure.
+    const INSTALL_GAS_CEILING: u64 = 150_000_000_000;
+    const INCREMENT_GAS_CEILING: u64 = 5_000_000_000;
+    const GAS_REPORT_PATH: &str = "gas_report.json";
+
+    /// Fails the test if `gas_cost` exceeds `ceiling`, so a gas regression fails CI instead of
+    /// silently shipping a more expensive deploy.
+    fn assert_gas_under_ceiling(label: &str, gas_cost: u64, ceiling: u64) {
+        assert!(
+            gas_cost <= ceiling,
+            "{label} consumed {gas_cost} gas, exceeding the {ceiling} ceiling"
+        );
+    }
+
+    /// Writes a small `gas_report.json` summarizing the gas cost of each entry point exercised
+    /// by `entries`, so gas cost can be tracked over time alongside the pass/fail test result.
+    fn write_gas_report(entries: &[(&str, u64)]) {
+        let mut report = String::from("{\n");
+        for (index, (label, gas_cost)) in entries.iter().enumerate() {
+            report.push_str(&format!("  \"{label}\": {gas_cost}"));
+            if index + 1 < entries.len() {
+                report.push(',');
+            }
+            report.push('\n');
+        }
+        report.push_str("}\n");
+        fs::write(GAS_REPORT_PATH, report).expect("should write gas_report.json");
+    }
 
     #[test]
     fn test_counting() {
@@ -49,11 +82,308 @@ mod tests {
             *DEFAULT_ACCOUNT_ADDR,
             contract_hash,
             "increment_count",
-            runtime_args! {},
+            runtime_args! { "step" => 1u32 },
+        )
+        .build();
+        builder.exec(increment_request).expect_success().commit();
+
+        let count_key = builder
+            .get_contract(contract_hash)
+            .expect("Not able to find contract")
+            .named_keys()
+            .get("count_key")
+            .expect("Unable to find count_key")
+            .clone();
+
+        let count_key_value = builder
+            .query(None, count_key, &[])
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t::<u32>()
+            .expect("should be u32.");
+
+        assert_eq!(count_key_value, 1);
+    }
+
+    #[test]
+    fn test_upgrade_preserves_count_across_versions() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        // Install v1 and bump the counter once before upgrading.
+        let install_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CONTRACT_WASM, runtime_args! {})
+                .build();
+        builder.exec(install_request).expect_success().commit();
+
+        let package_hash = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get("counter_package")
+            .expect("must have package hash key as part of contract creation")
+            .into_hash()
+            .map(ContractPackageHash::new)
+            .expect("must get package hash");
+
+        let contract_hash = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get("counter_contract_hash")
+            .expect("must have contract hash key as part of contract creation")
+            .into_hash()
+            .map(ContractHash::new)
+            .expect("must get contract hash");
+
+        let increment_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            contract_hash,
+            "increment_count",
+            runtime_args! { "step" => 1u32 },
         )
         .build();
         builder.exec(increment_request).expect_success().commit();
 
+        // Upgrade to v2 through the package and confirm the version advanced while the count
+        // survived.
+        let upgrade_to_v2_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CONTRACT_V2_WASM, runtime_args! {})
+                .build();
+        builder.exec(upgrade_to_v2_request).expect_success().commit();
+
+        let contract_package = builder
+            .get_contract_package(package_hash)
+            .expect("should have contract package");
+        assert_eq!(
+            contract_package.current_contract_version(),
+            Some(2),
+            "contract_version should have advanced to 2 after the upgrade"
+        );
+
+        let contract_hash_v2 = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get("counter_contract_hash")
+            .expect("must have contract hash key after upgrading")
+            .into_hash()
+            .map(ContractHash::new)
+            .expect("must get v2 contract hash");
+
+        let count_key_v2 = builder
+            .get_contract(contract_hash_v2)
+            .expect("Not able to find v2 contract")
+            .named_keys()
+            .get("count_key")
+            .expect("Unable to find count_key on v2")
+            .clone();
+
+        let count_after_upgrade = builder
+            .query(None, count_key_v2.clone(), &[])
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t::<u32>()
+            .expect("should be u32.");
+        assert_eq!(
+            count_after_upgrade, 1,
+            "count_key should retain its pre-upgrade value"
+        );
+
+        // The upgraded version must still honor the step argument and the zero-step guard from
+        // chunk0-5, and must still emit a `CountIncremented` event from chunk0-3 — a regression
+        // here would mean the upgrade silently reverted to the pre-chunk0-5/-3 behavior.
+        let increment_on_v2_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            contract_hash_v2,
+            "increment_count",
+            runtime_args! { "step" => 3u32 },
+        )
+        .build();
+        builder
+            .exec(increment_on_v2_request)
+            .expect_success()
+            .commit();
+
+        let count_after_v2_increment = builder
+            .query(None, count_key_v2.clone(), &[])
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t::<u32>()
+            .expect("should be u32.");
+        assert_eq!(count_after_v2_increment, 4);
+
+        let events_dict_uref_v2 = builder
+            .get_contract(contract_hash_v2)
+            .expect("Not able to find v2 contract")
+            .named_keys()
+            .get("__events")
+            .expect("Unable to find __events on v2")
+            .into_uref()
+            .expect("__events should be a URef");
+
+        let (_event_name, event_count): (String, u32) = builder
+            .query_dictionary_item(None, events_dict_uref_v2, "0")
+            .expect("should find event at index 0 emitted before the upgrade")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t()
+            .expect("should be (String, u32).");
+        assert_eq!(
+            event_count, 1,
+            "the event emitted before the upgrade should still be readable afterwards"
+        );
+
+        let zero_step_on_v2_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            contract_hash_v2,
+            "increment_count",
+            runtime_args! { "step" => 0u32 },
+        )
+        .build();
+        builder.exec(zero_step_on_v2_request).commit();
+        let error = builder.get_error().expect("should have an error");
+        assert!(matches!(
+            error,
+            EngineStateError::Exec(execution::Error::Revert(ApiError::User(2)))
+        ));
+
+        // Exercise a v2-only entry point to make sure the new version is actually live.
+        let decrement_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            contract_hash_v2,
+            "decrement_count",
+            runtime_args! { "step" => 4u32 },
+        )
+        .build();
+        builder.exec(decrement_request).expect_success().commit();
+
+        let count_after_decrement = builder
+            .query(None, count_key_v2, &[])
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t::<u32>()
+            .expect("should be u32.");
+        assert_eq!(count_after_decrement, 0);
+
+        // Upgrade again to v3 and confirm the version advanced once more while the (now zero)
+        // count is still the same underlying URef.
+        let upgrade_to_v3_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CONTRACT_V3_WASM, runtime_args! {})
+                .build();
+        builder.exec(upgrade_to_v3_request).expect_success().commit();
+
+        let contract_package = builder
+            .get_contract_package(package_hash)
+            .expect("should have contract package");
+        assert_eq!(
+            contract_package.current_contract_version(),
+            Some(3),
+            "contract_version should have advanced to 3 after the second upgrade"
+        );
+
+        let contract_hash_v3 = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get("counter_contract_hash")
+            .expect("must have contract hash key after the second upgrade")
+            .into_hash()
+            .map(ContractHash::new)
+            .expect("must get v3 contract hash");
+
+        // The step argument, zero-step guard, and event log must all still carry over on v3.
+        let increment_on_v3_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            contract_hash_v3,
+            "increment_count",
+            runtime_args! { "step" => 2u32 },
+        )
+        .build();
+        builder
+            .exec(increment_on_v3_request)
+            .expect_success()
+            .commit();
+
+        let count_key_v3 = builder
+            .get_contract(contract_hash_v3)
+            .expect("Not able to find v3 contract")
+            .named_keys()
+            .get("count_key")
+            .expect("Unable to find count_key on v3")
+            .clone();
+
+        let count_after_v3_increment = builder
+            .query(None, count_key_v3, &[])
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t::<u32>()
+            .expect("should be u32.");
+        assert_eq!(count_after_v3_increment, 2);
+
+        let events_dict_uref_v3 = builder
+            .get_contract(contract_hash_v3)
+            .expect("Not able to find v3 contract")
+            .named_keys()
+            .get("__events")
+            .expect("Unable to find __events on v3")
+            .into_uref()
+            .expect("__events should be a URef");
+
+        let (_event_name, event_count_at_index_1): (String, u32) = builder
+            .query_dictionary_item(None, events_dict_uref_v3, "1")
+            .expect("should find the v2 increment's event at index 1")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t()
+            .expect("should be (String, u32).");
+        assert_eq!(event_count_at_index_1, 4);
+    }
+
+    #[test]
+    fn test_counter_call_invokes_increment_via_contract_to_contract_call() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let counter_installation_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CONTRACT_WASM, runtime_args! {})
+                .build();
+        builder
+            .exec(counter_installation_request)
+            .expect_success()
+            .commit();
+
+        let contract_hash = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get("counter_contract_hash")
+            .expect("must have contract hash key as part of contract creation")
+            .into_hash()
+            .map(ContractHash::new)
+            .expect("must get contract hash");
+
+        let counter_call_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_CALL_WASM,
+            runtime_args! {
+                "counter_contract_hash" => contract_hash,
+            },
+        )
+        .build();
+        builder.exec(counter_call_request).expect_success().commit();
+
         let count_key = builder
             .get_contract(contract_hash)
             .expect("Not able to find contract")
@@ -73,4 +403,197 @@ mod tests {
 
         assert_eq!(count_key_value, 1);
     }
+
+    #[test]
+    fn test_increment_emits_count_incremented_event() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let counter_installation_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CONTRACT_WASM, runtime_args! {})
+                .build();
+        builder
+            .exec(counter_installation_request)
+            .expect_success()
+            .commit();
+
+        let contract_hash = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get("counter_contract_hash")
+            .expect("must have contract hash key as part of contract creation")
+            .into_hash()
+            .map(ContractHash::new)
+            .expect("must get contract hash");
+
+        let increment_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            contract_hash,
+            "increment_count",
+            runtime_args! { "step" => 1u32 },
+        )
+        .build();
+        builder.exec(increment_request).expect_success().commit();
+
+        let contract = builder
+            .get_contract(contract_hash)
+            .expect("Not able to find contract");
+
+        let events_dict_uref = contract
+            .named_keys()
+            .get("__events")
+            .expect("Unable to find __events")
+            .into_uref()
+            .expect("__events should be a URef");
+
+        let (event_name, event_count): (String, u32) = builder
+            .query_dictionary_item(None, events_dict_uref, "0")
+            .expect("should find event at index 0")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t()
+            .expect("should be (String, u32).");
+
+        assert_eq!(event_name, "CountIncremented");
+        assert_eq!(event_count, 1);
+    }
+
+    #[test]
+    fn test_gas_costs_stay_under_ceiling() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let counter_installation_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CONTRACT_WASM, runtime_args! {})
+                .build();
+        builder
+            .exec(counter_installation_request)
+            .expect_success()
+            .commit();
+        let install_gas_cost = builder.last_exec_gas_cost().value().as_u64();
+        assert_gas_under_ceiling("install", install_gas_cost, INSTALL_GAS_CEILING);
+
+        let contract_hash = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get("counter_contract_hash")
+            .expect("must have contract hash key as part of contract creation")
+            .into_hash()
+            .map(ContractHash::new)
+            .expect("must get contract hash");
+
+        let increment_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            contract_hash,
+            "increment_count",
+            runtime_args! { "step" => 1u32 },
+        )
+        .build();
+        builder.exec(increment_request).expect_success().commit();
+        let increment_gas_cost = builder.last_exec_gas_cost().value().as_u64();
+        assert_gas_under_ceiling("increment_count", increment_gas_cost, INCREMENT_GAS_CEILING);
+
+        write_gas_report(&[
+            ("install", install_gas_cost),
+            ("increment_count", increment_gas_cost),
+        ]);
+    }
+
+    #[test]
+    fn test_increment_count_by_step() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let counter_installation_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CONTRACT_WASM, runtime_args! {})
+                .build();
+        builder
+            .exec(counter_installation_request)
+            .expect_success()
+            .commit();
+
+        let contract_hash = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get("counter_contract_hash")
+            .expect("must have contract hash key as part of contract creation")
+            .into_hash()
+            .map(ContractHash::new)
+            .expect("must get contract hash");
+
+        let increment_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            contract_hash,
+            "increment_count",
+            runtime_args! { "step" => 5u32 },
+        )
+        .build();
+        builder.exec(increment_request).expect_success().commit();
+
+        let count_key = builder
+            .get_contract(contract_hash)
+            .expect("Not able to find contract")
+            .named_keys()
+            .get("count_key")
+            .expect("Unable to find count_key")
+            .clone();
+
+        let count_key_value = builder
+            .query(None, count_key, &[])
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t::<u32>()
+            .expect("should be u32.");
+
+        assert_eq!(count_key_value, 5);
+    }
+
+    #[test]
+    fn test_increment_count_with_zero_step_reverts() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let counter_installation_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CONTRACT_WASM, runtime_args! {})
+                .build();
+        builder
+            .exec(counter_installation_request)
+            .expect_success()
+            .commit();
+
+        let contract_hash = builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .named_keys()
+            .get("counter_contract_hash")
+            .expect("must have contract hash key as part of contract creation")
+            .into_hash()
+            .map(ContractHash::new)
+            .expect("must get contract hash");
+
+        let increment_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            contract_hash,
+            "increment_count",
+            runtime_args! { "step" => 0u32 },
+        )
+        .build();
+        builder.exec(increment_request).commit();
+
+        let error = builder.get_error().expect("should have an error");
+        assert!(matches!(
+            error,
+            EngineStateError::Exec(execution::Error::Revert(ApiError::User(2)))
+        ));
+    }
 }
@@ -0,0 +1,269 @@
+fn main() {
+    panic!("Execute \"cargo test --features livenet\" to run the livenet tests, not \"cargo run\".");
+}
+
+// These tests replay the same `count == 1` invariant as `integration_tests.rs`, but against a
+// real node over RPC instead of `InMemoryWasmTestBuilder`, so a contract can be validated
+// against real consensus before it ships. They are gated behind the `livenet` feature so that
+// `cargo test` stays fully offline by default.
+//
+// Written against the `casper_client::cli` surface as documented on docs.rs; this repo has no
+// Cargo.toml pinning a `casper-client` version, so double-check these signatures against the
+// version actually in use before running `cargo test --features livenet` for the first time.
+#[cfg(all(test, feature = "livenet"))]
+mod tests {
+    use std::{env, thread, time::Duration};
+
+    use casper_client::{
+        cli::{
+            get_deploy, get_state_root_hash, put_deploy, query_global_state, CliError,
+            DeployStrParams, GlobalStateStrParams, PaymentStrParams, SessionStrParams,
+        },
+        JsonRpcId, Verbosity,
+    };
+    use casper_types::{ExecutionResult, Key, PublicKey, SecretKey};
+
+    const CONTRACT_WASM: &str = "contract.wasm";
+    const PAYMENT_AMOUNT: &str = "3000000000";
+    const DEPLOY_POLL_INTERVAL: Duration = Duration::from_secs(2);
+    const DEPLOY_POLL_ATTEMPTS: u32 = 30;
+
+    fn node_rpc_url() -> String {
+        env::var("LIVENET_NODE_RPC_URL")
+            .expect("LIVENET_NODE_RPC_URL must point at a node's JSON-RPC endpoint")
+    }
+
+    fn chain_name() -> String {
+        env::var("LIVENET_CHAIN_NAME").expect("LIVENET_CHAIN_NAME must be set, e.g. \"casper-net-1\"")
+    }
+
+    fn funded_secret_key_path() -> String {
+        env::var("LIVENET_SECRET_KEY_PATH")
+            .expect("LIVENET_SECRET_KEY_PATH must point at a funded account's secret key file")
+    }
+
+    /// Deploys `contract.wasm` to a running node, waits for it to execute, then queries
+    /// `counter_contract_hash` and `count_key` through the node's state queries and asserts the
+    /// same invariant the in-memory `test_counting` test checks.
+    #[tokio::test]
+    async fn test_counting_against_live_node() {
+        let rpc_url = node_rpc_url();
+        let chain_name = chain_name();
+        let secret_key_path = funded_secret_key_path();
+
+        let install_deploy_hash =
+            deploy_contract(&rpc_url, &chain_name, &secret_key_path, CONTRACT_WASM)
+                .await
+                .expect("install deploy should be accepted by the node");
+        wait_for_deploy(&rpc_url, &install_deploy_hash)
+            .await
+            .expect("install deploy should execute");
+
+        let contract_hash =
+            query_account_named_key_as_hash(&rpc_url, &secret_key_path, "counter_contract_hash")
+                .await
+                .expect("should find counter_contract_hash on the installing account");
+
+        let increment_deploy_hash = call_entry_point(
+            &rpc_url,
+            &chain_name,
+            &secret_key_path,
+            &contract_hash,
+            "increment_count",
+            &["step:u32='1'"],
+        )
+        .await
+        .expect("increment deploy should be accepted by the node");
+        wait_for_deploy(&rpc_url, &increment_deploy_hash)
+            .await
+            .expect("increment deploy should execute");
+
+        let count: u32 = query_contract_named_key(&rpc_url, &contract_hash, "count_key")
+            .await
+            .expect("should query count_key from the live node");
+
+        assert_eq!(count, 1);
+    }
+
+    /// Submits a `put_deploy` installing `wasm_path` with no session args and returns the
+    /// resulting deploy hash.
+    async fn deploy_contract(
+        rpc_url: &str,
+        chain_name: &str,
+        secret_key_path: &str,
+        wasm_path: &str,
+    ) -> Result<String, CliError> {
+        let deploy_params = DeployStrParams {
+            secret_key: secret_key_path,
+            chain_name,
+            ..Default::default()
+        };
+        // `session_args_json` is a JSON-encoded string of args, not a `Vec`; "" means none.
+        let session_params = SessionStrParams::with_path(wasm_path, vec![], "");
+        let payment_params = PaymentStrParams::with_amount(PAYMENT_AMOUNT);
+
+        let response = put_deploy(
+            JsonRpcId::from(1),
+            rpc_url,
+            Verbosity::Low,
+            deploy_params,
+            session_params,
+            payment_params,
+        )
+        .await?;
+
+        Ok(response.result.deploy_hash.to_string())
+    }
+
+    /// Submits a `put_deploy` that calls `entry_point` on `contract_hash` with `session_args`
+    /// (each in the CLI's `name:type='value'` simple-arg syntax), mirroring
+    /// `contract_call_by_hash` in the in-memory tests.
+    async fn call_entry_point(
+        rpc_url: &str,
+        chain_name: &str,
+        secret_key_path: &str,
+        contract_hash: &str,
+        entry_point: &str,
+        session_args: &[&str],
+    ) -> Result<String, CliError> {
+        let deploy_params = DeployStrParams {
+            secret_key: secret_key_path,
+            chain_name,
+            ..Default::default()
+        };
+        // `session_args_json` is a JSON-encoded string of args, not a `Vec`; "" means none.
+        let session_params =
+            SessionStrParams::with_hash(contract_hash, entry_point, session_args.to_vec(), "");
+        let payment_params = PaymentStrParams::with_amount(PAYMENT_AMOUNT);
+
+        let response = put_deploy(
+            JsonRpcId::from(1),
+            rpc_url,
+            Verbosity::Low,
+            deploy_params,
+            session_params,
+            payment_params,
+        )
+        .await?;
+
+        Ok(response.result.deploy_hash.to_string())
+    }
+
+    /// Polls `get_deploy` until the node reports an execution result, failing the test if the
+    /// deploy reverts or never finalizes within `DEPLOY_POLL_ATTEMPTS`.
+    async fn wait_for_deploy(rpc_url: &str, deploy_hash: &str) -> Result<(), CliError> {
+        for _ in 0..DEPLOY_POLL_ATTEMPTS {
+            let response = get_deploy(JsonRpcId::from(1), rpc_url, Verbosity::Low, deploy_hash, false)
+                .await?;
+
+            if let Some(execution_results) = response.result.execution_results.first() {
+                return match &execution_results.result {
+                    ExecutionResult::Success { .. } => Ok(()),
+                    ExecutionResult::Failure { error_message, .. } => {
+                        panic!("deploy {deploy_hash} reverted: {error_message}")
+                    }
+                };
+            }
+
+            thread::sleep(DEPLOY_POLL_INTERVAL);
+        }
+
+        panic!("deploy {deploy_hash} did not execute within {DEPLOY_POLL_ATTEMPTS} poll attempts");
+    }
+
+    /// Builds the typed state-root-hash variant of `GlobalStateStrParams` that
+    /// `query_global_state` expects, rather than hand-formatting a CLI flag string.
+    fn state_root_hash_params(state_root_hash: &str) -> GlobalStateStrParams<'_> {
+        GlobalStateStrParams {
+            is_block_hash: false,
+            hash_value: state_root_hash,
+        }
+    }
+
+    /// Queries the installing account's named keys for `named_key` and returns it as a
+    /// contract hash string suitable for `SessionStrParams::with_hash`.
+    async fn query_account_named_key_as_hash(
+        rpc_url: &str,
+        secret_key_path: &str,
+        named_key: &str,
+    ) -> Result<String, CliError> {
+        let state_root_hash = current_state_root_hash(rpc_url).await?;
+
+        let secret_key =
+            SecretKey::from_file(secret_key_path).expect("should read funded secret key");
+        let account_hash = PublicKey::from(&secret_key).to_account_hash();
+        // Casper's formatted-string `Key` representation, e.g. "account-hash-<hex>".
+        let account_key = format!("account-hash-{}", to_hex(account_hash.as_bytes()));
+
+        let response = query_global_state(
+            JsonRpcId::from(1),
+            rpc_url,
+            Verbosity::Low,
+            state_root_hash_params(&state_root_hash),
+            &account_key,
+            vec![],
+        )
+        .await?;
+
+        let key = response
+            .result
+            .stored_value
+            .as_account()
+            .and_then(|account| account.named_keys().get(named_key))
+            .unwrap_or_else(|| panic!("{named_key} should be present on the installing account"));
+
+        Ok(match key {
+            Key::Hash(hash) => to_hex(&hash),
+            other => panic!("expected {named_key} to be a Key::Hash, got {other:?}"),
+        })
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    /// Queries `named_key` on the contract identified by `contract_hash` and decodes it as `T`,
+    /// mirroring `builder.query` in the in-memory tests.
+    async fn query_contract_named_key<T>(
+        rpc_url: &str,
+        contract_hash: &str,
+        named_key: &str,
+    ) -> Result<T, CliError>
+    where
+        T: casper_types::bytesrepr::FromBytes + casper_types::CLTyped,
+    {
+        let state_root_hash = current_state_root_hash(rpc_url).await?;
+        let contract_key = format!("hash-{contract_hash}");
+
+        let response = query_global_state(
+            JsonRpcId::from(1),
+            rpc_url,
+            Verbosity::Low,
+            state_root_hash_params(&state_root_hash),
+            &contract_key,
+            vec![named_key.to_string()],
+        )
+        .await?;
+
+        let cl_value = response
+            .result
+            .stored_value
+            .as_cl_value()
+            .unwrap_or_else(|| panic!("{named_key} should be a CLValue"));
+
+        Ok(cl_value
+            .clone()
+            .into_t()
+            .unwrap_or_else(|_| panic!("{named_key} should decode to the expected type")))
+    }
+
+    async fn current_state_root_hash(rpc_url: &str) -> Result<String, CliError> {
+        let response =
+            get_state_root_hash(JsonRpcId::from(1), rpc_url, Verbosity::Low, "").await?;
+        Ok(response
+            .result
+            .state_root_hash
+            .expect("state root hash should be present")
+            .to_string())
+    }
+}